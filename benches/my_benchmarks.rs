@@ -2,39 +2,36 @@ use std::hint::black_box;
 use criterion::{criterion_group, criterion_main, Criterion};
 use so_buff::Buffer;
 
-
 fn criterion_benchmark(c: &mut Criterion) {
-    c.bench_function("my_buff 10", my_buffer(black_box(10)));
-    c.bench_function("my_buff 100", my_buffer(black_box(10)));
-    c.bench_function("my_buff 1000", my_buffer(black_box(10)));
-    c.bench_function("my_buff 10000", my_buffer(black_box(10)));
-
-    c.bench_function("my_buff 10", vector(black_box(10)));
-    c.bench_function("my_buff 100", vector(black_box(10)));
-    c.bench_function("my_buff 1000", vector(black_box(10)));
-    c.bench_function("my_buff 10000", vector(black_box(10)));
+    c.bench_function("my_buff 10", |b| b.iter(|| my_buffer::<10>(black_box(10))));
+    c.bench_function("my_buff 100", |b| b.iter(|| my_buffer::<100>(black_box(100))));
+    c.bench_function("my_buff 1000", |b| b.iter(|| my_buffer::<1000>(black_box(1000))));
+    c.bench_function("my_buff 10000", |b| b.iter(|| my_buffer::<10000>(black_box(10000))));
+
+    c.bench_function("vector 10", |b| b.iter(|| vector(black_box(10))));
+    c.bench_function("vector 100", |b| b.iter(|| vector(black_box(100))));
+    c.bench_function("vector 1000", |b| b.iter(|| vector(black_box(1000))));
+    c.bench_function("vector 10000", |b| b.iter(|| vector(black_box(10000))));
 }
 
 criterion_group!(benches, criterion_benchmark);
 criterion_main!(benches);
 
+fn my_buffer<const N: usize>(items: usize) {
 
-
-fn my_buffer(items: usize) {
-
-    let mut buffer: Buffer<i32, items> = Buffer::new();
+    let mut buffer: Buffer<i32, N> = Buffer::new();
 
     for i in 0..items {
-        buffer.push(i);
+        let _ = buffer.push(i as i32);
     }
 
     // consume
 
-    buffer.into_iter();
-
+    let mut sum = 0;
     for message in buffer {
-        drop(message);
+        sum += message;
     }
+    black_box(sum);
 }
 
 fn vector(items: usize) {
@@ -47,9 +44,9 @@ fn vector(items: usize) {
 
     // consume
 
-    buffer.into_iter();
-
+    let mut sum = 0;
     for message in buffer {
-        drop(message);
+        sum += message;
     }
+    black_box(sum);
 }
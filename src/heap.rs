@@ -0,0 +1,219 @@
+//! A fixed-capacity, allocation-free binary max-heap.
+
+use core::mem::{self, MaybeUninit};
+
+use crate::Error;
+
+type Result<T, R> = core::result::Result<T, Error<R>>;
+
+/// A capacity-bounded binary max-heap, backed by the same `MaybeUninit`
+/// storage as [`Buffer`](crate::Buffer).
+pub struct BinaryHeap<T: Ord, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T: Ord, const N: usize> BinaryHeap<T, N> {
+    /// Returning a new, empty heap.
+    /// Need to specify type and size.
+    ///
+    ///```rust
+    /// # use so_buff::heap::BinaryHeap;
+    /// let mut heap: BinaryHeap<i32, 10> = BinaryHeap::new();
+    ///
+    /// heap.push(1);
+    /// heap.push(2);
+    /// heap.push(3);
+    ///```
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        let data = [const { MaybeUninit::uninit() }; N];
+        Self { data, len: 0 }
+    }
+
+    /// Pushes a value onto the heap, restoring the heap invariant by
+    /// sifting it up towards the root.
+    /// Will return an error containing the value if
+    /// the caller tried to push when the heap is full.
+    ///
+    ///```rust
+    /// # use so_buff::{heap::BinaryHeap, Error};
+    /// let mut heap = BinaryHeap::<i32, 2>::new();
+    ///
+    /// let _ = heap.push(1);
+    /// let _ = heap.push(2);
+    /// let should_fail = heap.push(3);
+    ///
+    /// assert_eq!(Err(Error::BufferIsFull(3)), should_fail);
+    ///```
+    #[must_use = "May fail if there is no space left"]
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.len >= N {
+            return Err(Error::BufferIsFull(value));
+        }
+
+        self.data[self.len].write(value);
+        self.len += 1;
+        self.sift_up(self.len - 1);
+
+        Ok(())
+    }
+
+    /// Removes and returns the largest item in the heap,
+    /// or `None` if the heap is empty.
+    ///
+    ///```rust
+    /// # use so_buff::heap::BinaryHeap;
+    /// let mut heap = BinaryHeap::<i32, 3>::new();
+    /// let _ = heap.push(1);
+    /// let _ = heap.push(3);
+    /// let _ = heap.push(2);
+    ///
+    /// assert_eq!(Some(3), heap.pop());
+    /// assert_eq!(Some(2), heap.pop());
+    /// assert_eq!(Some(1), heap.pop());
+    /// assert_eq!(None, heap.pop());
+    ///```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        self.data.swap(0, self.len);
+        let value = mem::replace(&mut self.data[self.len], MaybeUninit::uninit());
+
+        if self.len > 0 {
+            self.sift_down(0);
+        }
+
+        // SAFETY: index self.len (post-decrement) was within the
+        // pre-decrement 0..self.len and therefore initialized.
+        Some(unsafe { value.assume_init() })
+    }
+
+    /// Returns a reference to the largest item in the heap without
+    /// removing it, or `None` if the heap is empty. O(1).
+    pub fn peek(&self) -> Option<&T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        // SAFETY: index 0 is initialized whenever len > 0.
+        Some(unsafe { self.data[0].assume_init_ref() })
+    }
+
+    /// The amount of items currently in the heap.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the heap contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The maximum amount of items the heap can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Moves the item at `index` up towards the root until the heap
+    /// invariant holds, i.e. every parent is >= its children.
+    fn sift_up(&mut self, mut index: usize) {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+
+            // SAFETY: index and parent are both < len, and therefore initialized.
+            let should_swap =
+                unsafe { self.data[index].assume_init_ref() > self.data[parent].assume_init_ref() };
+
+            if !should_swap {
+                break;
+            }
+
+            self.data.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    /// Moves the item at `index` down towards the leaves until the heap
+    /// invariant holds, i.e. every parent is >= its children.
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let left = 2 * index + 1;
+            let right = 2 * index + 2;
+            let mut largest = index;
+
+            // SAFETY: left/right/largest are only compared once checked < len,
+            // and every index < len is initialized.
+            unsafe {
+                if left < self.len
+                    && self.data[left].assume_init_ref() > self.data[largest].assume_init_ref()
+                {
+                    largest = left;
+                }
+                if right < self.len
+                    && self.data[right].assume_init_ref() > self.data[largest].assume_init_ref()
+                {
+                    largest = right;
+                }
+            }
+
+            if largest == index {
+                break;
+            }
+
+            self.data.swap(index, largest);
+            index = largest;
+        }
+    }
+}
+
+impl<T: Ord, const N: usize> Drop for BinaryHeap<T, N> {
+    fn drop(&mut self) {
+        let slice = core::ptr::slice_from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), self.len);
+        unsafe { slice.drop_in_place() };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn push_and_pop_in_priority_order() {
+        let mut heap: BinaryHeap<i32, 5> = BinaryHeap::new();
+        let _ = heap.push(3);
+        let _ = heap.push(1);
+        let _ = heap.push(4);
+        let _ = heap.push(1);
+        let _ = heap.push(5);
+
+        assert_eq!(Some(5), heap.pop());
+        assert_eq!(Some(4), heap.pop());
+        assert_eq!(Some(3), heap.pop());
+        assert_eq!(Some(1), heap.pop());
+        assert_eq!(Some(1), heap.pop());
+        assert_eq!(None, heap.pop());
+    }
+
+    #[test]
+    fn push_to_full() {
+        let mut heap: BinaryHeap<i32, 2> = BinaryHeap::new();
+        assert_eq!(Ok(()), heap.push(1));
+        assert_eq!(Ok(()), heap.push(2));
+        assert_eq!(Err(Error::BufferIsFull(3)), heap.push(3));
+    }
+
+    #[test]
+    fn peek_does_not_remove() {
+        let mut heap: BinaryHeap<i32, 3> = BinaryHeap::new();
+        let _ = heap.push(1);
+        let _ = heap.push(2);
+
+        assert_eq!(Some(&2), heap.peek());
+        assert_eq!(Some(&2), heap.peek());
+        assert_eq!(2, heap.len());
+    }
+}
@@ -1,8 +1,16 @@
 //! The buffest buffer,
 
-use std::{mem::{self, MaybeUninit}, slice::from_raw_parts_mut};
+#![cfg_attr(not(test), no_std)]
 
-type Result<T, R> = std::result::Result<T, Error<R>>;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod heap;
+pub mod spsc;
+
+use core::{mem::{self, MaybeUninit}, ops::{Deref, DerefMut}, slice::{from_raw_parts, from_raw_parts_mut}};
+
+type Result<T, R> = core::result::Result<T, Error<R>>;
 
 pub struct Buffer<T, const N: usize> {
     data: [MaybeUninit<T>; N],
@@ -29,6 +37,28 @@ impl<T, const N: usize> Buffer<T, N> {
         Self { data, len: 0 }
     }
 
+    /// Builds a buffer from an iterator, pushing items one by one.
+    /// Will return an error containing the first item that did not fit
+    /// if the iterator yields more than `N` items.
+    ///
+    ///```rust
+    /// # use so_buff::{Buffer, Error};
+    /// let buf = Buffer::<i32, 3>::try_collect(1..=3).unwrap();
+    /// assert_eq!(&[1, 2, 3], buf.as_slice());
+    ///
+    /// let should_fail = Buffer::<i32, 3>::try_collect(1..=4);
+    /// assert_eq!(Err(Error::BufferIsFull(4)), should_fail);
+    ///```
+    pub fn try_collect<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, T> {
+        let mut buffer = Self::new();
+
+        for value in iter {
+            buffer.push(value)?;
+        }
+
+        Ok(buffer)
+    }
+
     /// Pushes a value into the buffer.
     /// Will return an error containing the value if
     /// the caller tried to push when the buffer is full.
@@ -56,11 +86,125 @@ impl<T, const N: usize> Buffer<T, N> {
         Ok(())
     }
 
+    /// Removes and returns the last item in the buffer,
+    /// or `None` if the buffer is empty.
+    ///
+    ///```rust
+    /// # use so_buff::Buffer;
+    /// let mut buf = Buffer::<i32, 3>::new();
+    /// let _ = buf.push(1);
+    /// let _ = buf.push(2);
+    ///
+    /// assert_eq!(Some(2), buf.pop());
+    /// assert_eq!(Some(1), buf.pop());
+    /// assert_eq!(None, buf.pop());
+    ///```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let value = mem::replace(&mut self.data[self.len], MaybeUninit::uninit());
+
+        // SAFETY: self.len was > 0, so index self.len (post-decrement) was
+        // within the pre-decrement 0..self.len and therefore initialized.
+        Some(unsafe { value.assume_init() })
+    }
+
+    /// The amount of items currently in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the buffer contains no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The maximum amount of items the buffer can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the initialized `0..self.len` items as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: 0..self.len is always initialized memory, see the `len` field.
+        unsafe { from_raw_parts(self.data.as_ptr().cast::<T>(), self.len) }
+    }
+
+    /// Returns the initialized `0..self.len` items as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: 0..self.len is always initialized memory, see the `len` field.
+        unsafe { from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), self.len) }
+    }
+
+}
+
+impl<T: Copy, const N: usize> Buffer<T, N> {
+    /// Appends a whole slice to the buffer with a single capacity check.
+    /// Will return an error if `src` does not fit in the remaining space,
+    /// in which case no elements are copied.
+    ///
+    ///```rust
+    /// # use so_buff::Buffer;
+    /// let mut buf = Buffer::<i32, 3>::new();
+    /// buf.extend_from_slice(&[1, 2, 3]).unwrap();
+    ///
+    /// assert_eq!(&[1, 2, 3], buf.as_slice());
+    /// assert!(buf.extend_from_slice(&[4]).is_err());
+    ///```
+    #[must_use = "May fail if there is no space left"]
+    pub fn extend_from_slice(&mut self, src: &[T]) -> Result<(), ()> {
+        if self.len + src.len() > N {
+            return Err(Error::BufferIsFull(()));
+        }
+
+        // SAFETY: the bounds check above guarantees `src` fits in the
+        // uninitialized tail of `self.data`, and `T: Copy` means the bytes
+        // can be duplicated without running destructors on the source.
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                src.as_ptr(),
+                self.data.as_mut_ptr().add(self.len).cast::<T>(),
+                src.len(),
+            );
+        }
+        self.len += src.len();
+
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> Deref for Buffer<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for Buffer<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for Buffer<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for Buffer<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
 }
 
 impl<T, const N: usize> Drop for Buffer<T, N> {
     fn drop(&mut self) {
-        let slice = std::ptr::slice_from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), self.len);
+        let slice = core::ptr::slice_from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), self.len);
         unsafe {slice.drop_in_place()};
     }
 }
@@ -100,7 +244,7 @@ impl<T, const N: usize> Iterator for IntoIter<T, N> {
             return None;
         }
 
-        let value = std::mem::replace(
+        let value = core::mem::replace(
             &mut self.buffer[self.current_index],
             MaybeUninit::uninit(),
         );
@@ -129,14 +273,57 @@ pub enum Error<T> {
     BufferIsFull(T),
 }
 
-impl<T: std::fmt::Debug> std::error::Error for Error<T> {}
+#[cfg(feature = "std")]
+impl<T: core::fmt::Debug> std::error::Error for Error<T> {}
 
-impl<T: std::fmt::Debug> std::fmt::Display for Error<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl<T: core::fmt::Debug> core::fmt::Display for Error<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self)
     }
 }
 
+/// Serializes as a sequence of the initialized `0..len` elements.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize, const N: usize> serde::Serialize for Buffer<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+/// Deserializes from a sequence, pushing elements one by one and erroring
+/// if the sequence yields more than `N` of them.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::Deserialize<'de> for Buffer<T, N> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> core::result::Result<Self, D::Error> {
+        struct BufferVisitor<T, const N: usize>(core::marker::PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>, const N: usize> serde::de::Visitor<'de> for BufferVisitor<T, N> {
+            type Value = Buffer<T, N>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(formatter, "a sequence of at most {N} elements")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> core::result::Result<Self::Value, A::Error> {
+                let mut buffer = Buffer::new();
+
+                while let Some(value) = seq.next_element()? {
+                    buffer
+                        .push(value)
+                        .map_err(|_| serde::de::Error::custom("sequence exceeds Buffer capacity"))?;
+                }
+
+                Ok(buffer)
+            }
+        }
+
+        deserializer.deserialize_seq(BufferVisitor(core::marker::PhantomData))
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -172,4 +359,58 @@ mod test {
         assert_eq!(Some(3), buf_iter.next());
         assert_eq!(None, buf_iter.next());
     }
+
+    #[test]
+    fn try_collect() {
+        let buffer = Buffer::<i32, 3>::try_collect(1..=3).unwrap();
+        assert_eq!(&[1, 2, 3], buffer.as_slice());
+
+        let should_fail = Buffer::<i32, 3>::try_collect(1..=4);
+        assert_eq!(Err(Error::BufferIsFull(4)), should_fail);
+    }
+
+    #[test]
+    fn extend_from_slice() {
+        let mut buffer = Buffer::<i32, 3>::new();
+        assert_eq!(Ok(()), buffer.extend_from_slice(&[1, 2, 3]));
+        assert_eq!(&[1, 2, 3], buffer.as_slice());
+
+        assert_eq!(Err(Error::BufferIsFull(())), buffer.extend_from_slice(&[4]));
+    }
+
+    #[test]
+    fn pop() {
+        let mut buffer: Buffer<i32, 3> = Buffer::new();
+        let _ = buffer.push(1);
+        let _ = buffer.push(2);
+
+        assert_eq!(Some(2), buffer.pop());
+        assert_eq!(Some(1), buffer.pop());
+        assert_eq!(None, buffer.pop());
+    }
+
+    #[test]
+    fn len_is_empty_capacity() {
+        let mut buffer: Buffer<i32, 3> = Buffer::new();
+        assert_eq!(0, buffer.len());
+        assert!(buffer.is_empty());
+        assert_eq!(3, buffer.capacity());
+
+        let _ = buffer.push(1);
+        assert_eq!(1, buffer.len());
+        assert!(!buffer.is_empty());
+    }
+
+    #[test]
+    fn as_slice_and_deref() {
+        let mut buffer: Buffer<i32, 3> = Buffer::new();
+        let _ = buffer.push(1);
+        let _ = buffer.push(2);
+
+        assert_eq!(&[1, 2], buffer.as_slice());
+        assert_eq!(&[1, 2], &*buffer);
+
+        buffer.as_mut_slice()[0] = 10;
+        assert_eq!(&[10, 2], &*buffer);
+    }
 }
@@ -0,0 +1,153 @@
+//! A lock-free single-producer/single-consumer ring-buffer queue.
+
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// A fixed-capacity ring buffer that can be [`split`](Queue::split) into a
+/// [`Producer`] and a [`Consumer`] which may be sent to different threads
+/// and communicate without locks.
+///
+/// One slot is always kept empty to distinguish a full queue from an empty
+/// one, so the usable capacity is `N - 1`.
+///
+///```rust
+/// # use so_buff::spsc::Queue;
+/// let mut queue: Queue<i32, 3> = Queue::new();
+/// let (mut producer, mut consumer) = queue.split();
+///
+/// producer.enqueue(1).unwrap();
+/// producer.enqueue(2).unwrap();
+///
+/// assert_eq!(Some(1), consumer.dequeue());
+/// assert_eq!(Some(2), consumer.dequeue());
+/// assert_eq!(None, consumer.dequeue());
+///```
+pub struct Queue<T, const N: usize> {
+    buffer: UnsafeCell<[MaybeUninit<T>; N]>,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// SAFETY: access to `buffer` is only ever performed through the `head`/`tail`
+// atomics, which hand each slot to exactly one of the producer or consumer
+// side at a time.
+unsafe impl<T: Send, const N: usize> Sync for Queue<T, N> {}
+
+impl<T, const N: usize> Queue<T, N> {
+    /// Creates a new, empty queue.
+    pub const fn new() -> Self {
+        Self {
+            buffer: UnsafeCell::new([const { MaybeUninit::uninit() }; N]),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    /// Splits the queue into a [`Producer`] and a [`Consumer`] handle.
+    pub fn split(&mut self) -> (Producer<'_, T, N>, Consumer<'_, T, N>) {
+        (Producer { queue: self }, Consumer { queue: self })
+    }
+}
+
+impl<T, const N: usize> Default for Queue<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for Queue<T, N> {
+    fn drop(&mut self) {
+        let head = *self.head.get_mut();
+        let tail = *self.tail.get_mut();
+
+        let buffer = self.buffer.get_mut();
+        let mut index = head;
+        while index != tail {
+            // SAFETY: every slot in `head..tail` (wrapping) holds an
+            // initialized `T` that has not yet been read out.
+            unsafe { buffer[index].assume_init_drop() };
+            index = (index + 1) % N;
+        }
+    }
+}
+
+/// The producing half of a [`Queue`], created by [`Queue::split`].
+pub struct Producer<'a, T, const N: usize> {
+    queue: &'a Queue<T, N>,
+}
+
+impl<T, const N: usize> Producer<'_, T, N> {
+    /// Enqueues `value`, returning it back if the queue is full.
+    #[must_use = "May fail if there is no space left"]
+    pub fn enqueue(&mut self, value: T) -> Result<(), T> {
+        let tail = self.queue.tail.load(Ordering::Relaxed);
+        let head = self.queue.head.load(Ordering::Acquire);
+        let next_tail = (tail + 1) % N;
+
+        if next_tail == head {
+            return Err(value);
+        }
+
+        // SAFETY: `next_tail != head` guarantees slot `tail` is not owned by
+        // the consumer, so we may write into it.
+        unsafe { (*self.queue.buffer.get())[tail].write(value) };
+        self.queue.tail.store(next_tail, Ordering::Release);
+
+        Ok(())
+    }
+}
+
+/// The consuming half of a [`Queue`], created by [`Queue::split`].
+pub struct Consumer<'a, T, const N: usize> {
+    queue: &'a Queue<T, N>,
+}
+
+impl<T, const N: usize> Consumer<'_, T, N> {
+    /// Dequeues the oldest value, or `None` if the queue is empty.
+    pub fn dequeue(&mut self) -> Option<T> {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        let tail = self.queue.tail.load(Ordering::Acquire);
+
+        if head == tail {
+            return None;
+        }
+
+        // SAFETY: `head != tail` guarantees slot `head` holds a value
+        // written by the producer that has not yet been read out.
+        let value = unsafe { (*self.queue.buffer.get())[head].assume_init_read() };
+        self.queue.head.store((head + 1) % N, Ordering::Release);
+
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn enqueue_dequeue() {
+        let mut queue: Queue<i32, 3> = Queue::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        assert_eq!(Ok(()), producer.enqueue(1));
+        assert_eq!(Ok(()), producer.enqueue(2));
+        assert_eq!(Some(1), consumer.dequeue());
+        assert_eq!(Some(2), consumer.dequeue());
+        assert_eq!(None, consumer.dequeue());
+    }
+
+    #[test]
+    fn enqueue_to_full() {
+        let mut queue: Queue<i32, 2> = Queue::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        assert_eq!(Ok(()), producer.enqueue(1));
+        assert_eq!(Err(2), producer.enqueue(2));
+
+        assert_eq!(Some(1), consumer.dequeue());
+        assert_eq!(Ok(()), producer.enqueue(3));
+        assert_eq!(Some(3), consumer.dequeue());
+    }
+}